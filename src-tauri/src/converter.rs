@@ -0,0 +1,729 @@
+//! Parsing of Open Hand History (OHH) JSON and conversion to tracker-friendly formats.
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read, Write};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OhhFile {
+    pub ohh: OhhHand,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OhhHand {
+    pub spec_version: String,
+    pub site_name: String,
+    #[serde(default)]
+    pub network_name: Option<String>,
+    pub game_type: String,
+    pub table_name: String,
+    pub table_size: u8,
+    pub game_number: String,
+    pub start_date_utc: String,
+    pub currency: String,
+    #[serde(default)]
+    pub ante_amount: f64,
+    pub small_blind_amount: f64,
+    pub big_blind_amount: f64,
+    pub dealer_seat: u8,
+    pub players: Vec<OhhPlayer>,
+    pub rounds: Vec<OhhRound>,
+    #[serde(default)]
+    pub pots: Vec<OhhPot>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OhhPlayer {
+    pub id: u32,
+    pub seat: u8,
+    pub name: String,
+    pub starting_stack: f64,
+    #[serde(default)]
+    pub is_hero: bool,
+    #[serde(default)]
+    pub cards: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OhhRound {
+    pub street: String,
+    #[serde(default)]
+    pub cards: Vec<String>,
+    pub actions: Vec<OhhAction>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OhhAction {
+    pub player_id: u32,
+    pub action: String,
+    #[serde(default)]
+    pub amount: f64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OhhPot {
+    pub number: u8,
+    pub amount: f64,
+    #[serde(default)]
+    pub rake: f64,
+    pub player_wins: Vec<OhhPlayerWin>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OhhPlayerWin {
+    pub player_id: u32,
+    pub win_amount: f64,
+}
+
+/// The shape a converted hand is rendered into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    #[default]
+    PokerStarsText,
+    GgPokerText,
+    Json,
+    Yaml,
+    CsvSummary,
+}
+
+/// Parses a single OHH JSON document (the `{"ohh": {...}}` envelope) into our hand model.
+pub fn parse_ohh(content: &str) -> Result<OhhHand, String> {
+    let file: OhhFile =
+        serde_json::from_str(content).map_err(|e| format!("failed to parse OHH JSON: {}", e))?;
+    Ok(file.ohh)
+}
+
+/// Converts a single OHH JSON document into PokerStars-style hand history text.
+pub fn convert_ohh_file(content: &str) -> Result<String, String> {
+    convert_ohh_file_as(content, OutputFormat::PokerStarsText)
+}
+
+/// Converts a single OHH JSON document into the given [`OutputFormat`].
+pub fn convert_ohh_file_as(content: &str, format: OutputFormat) -> Result<String, String> {
+    let hand = parse_ohh(content)?;
+    render_for_format(&hand, format)
+}
+
+/// A conversion result bundled with the structural diagnostics found while validating the hand,
+/// so callers can surface dropped or suspect hands instead of silently emitting bad output.
+#[derive(Debug, Serialize)]
+pub struct ConversionReport {
+    pub output: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Converts a single OHH JSON document into the given [`OutputFormat`], running the structural
+/// validation pass over it first.
+pub fn convert_ohh_file_with_report(
+    content: &str,
+    format: OutputFormat,
+) -> Result<ConversionReport, String> {
+    let hand = parse_ohh(content)?;
+    let diagnostics = validate_hand(&hand, 1);
+    let output = render_for_format(&hand, format)?;
+    Ok(ConversionReport { output, diagnostics })
+}
+
+fn render_for_format(hand: &OhhHand, format: OutputFormat) -> Result<String, String> {
+    match format {
+        OutputFormat::PokerStarsText => Ok(render_pokerstars_text(hand)),
+        OutputFormat::GgPokerText => Ok(render_ggpoker_text(hand)),
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(hand).map_err(|e| format!("failed to render JSON: {}", e))
+        }
+        OutputFormat::Yaml => {
+            serde_yaml::to_string(hand).map_err(|e| format!("failed to render YAML: {}", e))
+        }
+        OutputFormat::CsvSummary => Ok(render_csv_summary(hand)),
+    }
+}
+
+/// Outcome of [`convert_ohh_stream`]: how many hands were converted, plus the structural
+/// diagnostics for every hand that was validated along the way, so large multi-hand imports can
+/// surface dropped or suspect hands the same way [`convert_ohh_file_with_report`] does.
+#[derive(Debug, Serialize)]
+pub struct StreamConversionReport {
+    pub hands_converted: usize,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Converts hands one at a time from `reader`, flushing each to `writer` as soon as it's
+/// converted so memory use stays bounded regardless of input size. Accepts either a top-level
+/// JSON array of OHH documents or newline-delimited JSON (one OHH document per line). Each hand
+/// is validated via [`validate_hand`] as it's converted. Parse errors are reported with the
+/// 1-based index of the hand that failed.
+pub fn convert_ohh_stream<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+) -> Result<StreamConversionReport, String> {
+    let mut reader = BufReader::new(reader);
+    let mut writer = writer;
+    let (hands_converted, diagnostics) = match peek_first_significant_byte(&mut reader)? {
+        None => (0, Vec::new()),
+        Some(b'[') => convert_json_array_stream(reader, &mut writer)?,
+        Some(_) => convert_ndjson_stream(reader, &mut writer)?,
+    };
+    Ok(StreamConversionReport {
+        hands_converted,
+        diagnostics,
+    })
+}
+
+/// Consumes leading whitespace and returns the first remaining byte without consuming it.
+fn peek_first_significant_byte<R: BufRead>(reader: &mut R) -> Result<Option<u8>, String> {
+    loop {
+        let buf = reader
+            .fill_buf()
+            .map_err(|e| format!("failed to read input: {}", e))?;
+        if buf.is_empty() {
+            return Ok(None);
+        }
+        let skip = buf.iter().take_while(|b| b.is_ascii_whitespace()).count();
+        if skip < buf.len() {
+            reader.consume(skip);
+            let buf = reader
+                .fill_buf()
+                .map_err(|e| format!("failed to read input: {}", e))?;
+            return Ok(buf.first().copied());
+        }
+        reader.consume(skip);
+    }
+}
+
+fn convert_hand_to(
+    writer: &mut impl Write,
+    hand_index: usize,
+    hand_json: &str,
+) -> Result<Vec<Diagnostic>, String> {
+    let hand = parse_ohh(hand_json).map_err(|e| format!("hand {}: {}", hand_index, e))?;
+    let diagnostics = validate_hand(&hand, hand_index);
+    write_hand(writer, hand_index, &hand)?;
+    Ok(diagnostics)
+}
+
+/// Parses a single OHH JSON document from raw bytes, same as [`parse_ohh`] but without requiring
+/// the caller to have already validated the buffer as UTF-8 (serde_json validates internally).
+fn parse_ohh_bytes(bytes: &[u8]) -> Result<OhhHand, String> {
+    let file: OhhFile =
+        serde_json::from_slice(bytes).map_err(|e| format!("failed to parse OHH JSON: {}", e))?;
+    Ok(file.ohh)
+}
+
+fn convert_hand_bytes_to(
+    writer: &mut impl Write,
+    hand_index: usize,
+    hand_json: &[u8],
+) -> Result<Vec<Diagnostic>, String> {
+    let hand = parse_ohh_bytes(hand_json).map_err(|e| format!("hand {}: {}", hand_index, e))?;
+    let diagnostics = validate_hand(&hand, hand_index);
+    write_hand(writer, hand_index, &hand)?;
+    Ok(diagnostics)
+}
+
+fn write_hand(writer: &mut impl Write, hand_index: usize, hand: &OhhHand) -> Result<(), String> {
+    let rendered = render_pokerstars_text(hand);
+    writer
+        .write_all(rendered.as_bytes())
+        .and_then(|_| writer.write_all(b"\n"))
+        .map_err(|e| format!("failed to write hand {}: {}", hand_index, e))
+}
+
+/// Streams newline-delimited OHH JSON, converting and writing one hand per non-blank line.
+/// Tolerates a trailing partial or blank line at end of input.
+fn convert_ndjson_stream<R: BufRead, W: Write>(
+    mut reader: R,
+    writer: &mut W,
+) -> Result<(usize, Vec<Diagnostic>), String> {
+    let mut count = 0;
+    let mut diagnostics = Vec::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let bytes_read = reader
+            .read_line(&mut line)
+            .map_err(|e| format!("failed to read input: {}", e))?;
+        if bytes_read == 0 {
+            break;
+        }
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        // A final line with no terminating newline that doesn't parse is a hand cut off
+        // mid-write; stop there instead of failing the hands already converted.
+        if !line.ends_with('\n') && parse_ohh(trimmed).is_err() {
+            break;
+        }
+        count += 1;
+        diagnostics.extend(convert_hand_to(writer, count, trimmed)?);
+    }
+    Ok((count, diagnostics))
+}
+
+/// Streams a top-level JSON array of OHH documents, converting and writing one hand as soon as
+/// its closing brace is seen, without ever buffering the whole array in memory.
+fn convert_json_array_stream<R: BufRead, W: Write>(
+    mut reader: R,
+    writer: &mut W,
+) -> Result<(usize, Vec<Diagnostic>), String> {
+    let mut opening = [0u8; 1];
+    reader
+        .read_exact(&mut opening)
+        .map_err(|e| format!("failed to read input: {}", e))?;
+
+    let mut count = 0;
+    let mut diagnostics = Vec::new();
+    // Accumulated as raw bytes, not `char`s: multi-byte UTF-8 continuation bytes (0x80-0xBF)
+    // never collide with the ASCII structural bytes matched below, so scanning byte-by-byte is
+    // safe as long as we don't round-trip through `as char`/`String::push`, which would
+    // reinterpret each continuation byte as its own Latin-1 code point and corrupt the text.
+    let mut buf: Vec<u8> = Vec::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escape = false;
+
+    for byte in reader.bytes() {
+        let byte = byte.map_err(|e| format!("failed to read input: {}", e))?;
+
+        if in_string {
+            buf.push(byte);
+            if escape {
+                escape = false;
+            } else if byte == b'\\' {
+                escape = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' if depth > 0 => {
+                in_string = true;
+                buf.push(byte);
+            }
+            b'{' => {
+                depth += 1;
+                buf.push(byte);
+            }
+            b'}' => {
+                depth -= 1;
+                buf.push(byte);
+                if depth == 0 {
+                    count += 1;
+                    diagnostics.extend(convert_hand_bytes_to(writer, count, &buf)?);
+                    buf.clear();
+                }
+            }
+            b']' if depth == 0 => break,
+            _ if depth > 0 => buf.push(byte),
+            _ => {} // whitespace/commas between top-level elements
+        }
+    }
+
+    Ok((count, diagnostics))
+}
+
+fn player_by_id(hand: &OhhHand, player_id: u32) -> Option<&OhhPlayer> {
+    hand.players.iter().find(|p| p.id == player_id)
+}
+
+/// How serious a [`Diagnostic`] is: a warning flags something suspect but survivable, an error
+/// flags a hand that's structurally broken and likely unsafe to trust downstream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// One structural finding from [`validate_hand`], tagged with the 1-based index of the hand it
+/// came from so a multi-hand report can point back to the offending hand.
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    pub hand_index: usize,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// Parses a single OHH JSON document and checks its structural invariants (see [`validate_hand`]).
+pub fn validate_ohh_content(content: &str) -> Result<Vec<Diagnostic>, String> {
+    let hand = parse_ohh(content)?;
+    Ok(validate_hand(&hand, 1))
+}
+
+fn street_rank(street: &str) -> u8 {
+    match street.to_lowercase().as_str() {
+        "preflop" => 0,
+        "flop" => 1,
+        "turn" => 2,
+        "river" => 3,
+        _ => 0,
+    }
+}
+
+fn expected_board_cards(street: &str) -> usize {
+    match street.to_lowercase().as_str() {
+        "preflop" => 0,
+        "flop" => 3,
+        "turn" => 4,
+        "river" => 5,
+        _ => 0,
+    }
+}
+
+/// Checks structural invariants of `hand`: pot totals reconcile with bets and rake, every
+/// referenced player seat exists, the board has as many cards as the furthest street reached
+/// expects, and no card appears twice across hole cards and the board.
+pub fn validate_hand(hand: &OhhHand, hand_index: usize) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let valid_ids: std::collections::HashSet<u32> = hand.players.iter().map(|p| p.id).collect();
+
+    for round in &hand.rounds {
+        for action in &round.actions {
+            if !valid_ids.contains(&action.player_id) {
+                diagnostics.push(Diagnostic {
+                    hand_index,
+                    severity: Severity::Error,
+                    message: format!(
+                        "action on {} references unknown player id {}",
+                        round.street, action.player_id
+                    ),
+                });
+            }
+        }
+    }
+    for pot in &hand.pots {
+        for win in &pot.player_wins {
+            if !valid_ids.contains(&win.player_id) {
+                diagnostics.push(Diagnostic {
+                    hand_index,
+                    severity: Severity::Error,
+                    message: format!(
+                        "pot #{} win references unknown player id {}",
+                        pot.number, win.player_id
+                    ),
+                });
+            }
+        }
+    }
+
+    if !hand.pots.is_empty() {
+        // `OhhAction::amount` is the raise-to figure on multi-raise streets rather than the
+        // incremental chips committed, so summing it across a street over-counts relative to
+        // the pot total on any hand with more than one raise. That makes this check too noisy
+        // to trust as a hard error; flag it as a warning worth a human look instead of failing
+        // the hand outright.
+        let contributed: f64 = hand
+            .rounds
+            .iter()
+            .flat_map(|r| &r.actions)
+            .map(|a| a.amount)
+            .sum();
+        let pot_total: f64 = hand.pots.iter().map(|p| p.amount + p.rake).sum();
+        if (contributed - pot_total).abs() > 0.01 {
+            diagnostics.push(Diagnostic {
+                hand_index,
+                severity: Severity::Warning,
+                message: format!(
+                    "total bets ({:.2}) do not reconcile with pot total plus rake ({:.2}); \
+                     possibly expected if any street had more than one raise",
+                    contributed, pot_total
+                ),
+            });
+        }
+    }
+
+    if let Some(furthest_street) = hand
+        .rounds
+        .iter()
+        .map(|r| r.street.as_str())
+        .max_by_key(|street| street_rank(street))
+    {
+        let expected = expected_board_cards(furthest_street);
+        let actual: usize = hand.rounds.iter().flat_map(|r| r.cards.iter()).count();
+        if actual != expected {
+            diagnostics.push(Diagnostic {
+                hand_index,
+                severity: Severity::Warning,
+                message: format!(
+                    "board has {} card(s) but {} street expects {}",
+                    actual, furthest_street, expected
+                ),
+            });
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut duplicates = std::collections::HashSet::new();
+    for card in hand
+        .players
+        .iter()
+        .flat_map(|p| p.cards.iter())
+        .chain(hand.rounds.iter().flat_map(|r| r.cards.iter()))
+    {
+        if !seen.insert(card.as_str()) {
+            duplicates.insert(card.clone());
+        }
+    }
+    for card in duplicates {
+        diagnostics.push(Diagnostic {
+            hand_index,
+            severity: Severity::Error,
+            message: format!("card {} appears more than once across hole cards and board", card),
+        });
+    }
+
+    diagnostics
+}
+
+/// Renders [`OutputFormat::CsvSummary`]: a header line followed by one row for the hand with its
+/// id, stakes, hero's seat, hero's net result and the final board.
+fn render_csv_summary(hand: &OhhHand) -> String {
+    let hero = hand.players.iter().find(|p| p.is_hero);
+    let hero_seat = hero.map(|p| p.seat.to_string()).unwrap_or_default();
+
+    // `OhhAction::amount` is the raise-to figure for that street (see validate_hand's pot
+    // reconciliation note), so it's cumulative across a player's actions within one round, not
+    // incremental. Summing every action would double-count re-raises; take only the last
+    // amount-bearing action per round, which already reflects the player's total commitment for
+    // that street.
+    let contributed: f64 = hero
+        .map(|h| {
+            hand.rounds
+                .iter()
+                .filter_map(|r| {
+                    r.actions
+                        .iter()
+                        .filter(|a| a.player_id == h.id && a.amount > 0.0)
+                        .next_back()
+                        .map(|a| a.amount)
+                })
+                .sum()
+        })
+        .unwrap_or(0.0);
+
+    let won: f64 = hero
+        .map(|h| {
+            hand.pots
+                .iter()
+                .flat_map(|p| &p.player_wins)
+                .filter(|w| w.player_id == h.id)
+                .map(|w| w.win_amount)
+                .sum()
+        })
+        .unwrap_or(0.0);
+
+    let board = hand
+        .rounds
+        .iter()
+        .flat_map(|r| r.cards.iter())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut out = String::from("hand_id,stakes,hero_seat,net_result,board\n");
+    out.push_str(&format!(
+        "{},{}/{},{},{:.2},\"{}\"\n",
+        hand.game_number,
+        hand.small_blind_amount,
+        hand.big_blind_amount,
+        hero_seat,
+        won - contributed,
+        board
+    ));
+    out
+}
+
+fn render_pokerstars_text(hand: &OhhHand) -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!(
+        "PokerStars Hand #{}: {} ({}/{} {}) - {}\n",
+        hand.game_number,
+        hand.game_type,
+        hand.small_blind_amount,
+        hand.big_blind_amount,
+        hand.currency,
+        hand.start_date_utc
+    ));
+    out.push_str(&format!(
+        "Table '{}' {}-max Seat #{} is the button\n",
+        hand.table_name, hand.table_size, hand.dealer_seat
+    ));
+
+    for player in &hand.players {
+        out.push_str(&format!(
+            "Seat {}: {} ({} in chips)\n",
+            player.seat, player.name, player.starting_stack
+        ));
+    }
+
+    for round in &hand.rounds {
+        out.push_str(&format!("*** {} ***\n", round.street.to_uppercase()));
+        if !round.cards.is_empty() {
+            out.push_str(&format!("[{}]\n", round.cards.join(" ")));
+        }
+        for action in &round.actions {
+            let name = player_by_id(hand, action.player_id)
+                .map(|p| p.name.as_str())
+                .unwrap_or("Unknown");
+            if action.amount > 0.0 {
+                out.push_str(&format!("{}: {} {}\n", name, action.action, action.amount));
+            } else {
+                out.push_str(&format!("{}: {}\n", name, action.action));
+            }
+        }
+    }
+
+    if !hand.pots.is_empty() {
+        out.push_str("*** SUMMARY ***\n");
+        for pot in &hand.pots {
+            out.push_str(&format!(
+                "Total pot {} | Rake {}\n",
+                pot.amount, pot.rake
+            ));
+            for win in &pot.player_wins {
+                let name = player_by_id(hand, win.player_id)
+                    .map(|p| p.name.as_str())
+                    .unwrap_or("Unknown");
+                out.push_str(&format!("{} collected {}\n", name, win.win_amount));
+            }
+        }
+    }
+
+    out
+}
+
+fn render_ggpoker_text(hand: &OhhHand) -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!(
+        "Poker Hand #{}: {} ({}/{} {}) - {}\n",
+        hand.game_number,
+        hand.game_type,
+        hand.small_blind_amount,
+        hand.big_blind_amount,
+        hand.currency,
+        hand.start_date_utc
+    ));
+    out.push_str(&format!(
+        "Table '{}' {}-max Seat #{} is the button\n",
+        hand.table_name, hand.table_size, hand.dealer_seat
+    ));
+
+    for player in &hand.players {
+        let hero_tag = if player.is_hero { " [Hero]" } else { "" };
+        out.push_str(&format!(
+            "Seat {}: {}{} ({} in chips)\n",
+            player.seat, player.name, hero_tag, player.starting_stack
+        ));
+    }
+
+    for round in &hand.rounds {
+        out.push_str(&format!("*** {} ***\n", round.street.to_uppercase()));
+        if !round.cards.is_empty() {
+            out.push_str(&format!("[{}]\n", round.cards.join(" ")));
+        }
+        for action in &round.actions {
+            let name = player_by_id(hand, action.player_id)
+                .map(|p| p.name.as_str())
+                .unwrap_or("Unknown");
+            if action.amount > 0.0 {
+                out.push_str(&format!("{}: {} {}\n", name, action.action, action.amount));
+            } else {
+                out.push_str(&format!("{}: {}\n", name, action.action));
+            }
+        }
+    }
+
+    if !hand.pots.is_empty() {
+        out.push_str("*** SUMMARY ***\n");
+        for pot in &hand.pots {
+            out.push_str(&format!("Total pot {} | Rake {}\n", pot.amount, pot.rake));
+            for win in &pot.player_wins {
+                let name = player_by_id(hand, win.player_id)
+                    .map(|p| p.name.as_str())
+                    .unwrap_or("Unknown");
+                out.push_str(&format!("{} collected {}\n", name, win.win_amount));
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Builds a single-line OHH JSON document so it works unmodified as one NDJSON line, not
+    /// just as one array element.
+    fn sample_hand_json(table_name: &str, player_name: &str) -> String {
+        format!(
+            "{{\"ohh\":{{\"spec_version\":\"1.2.2\",\"site_name\":\"Test\",\
+             \"game_type\":\"Holdem\",\"table_name\":\"{table_name}\",\"table_size\":6,\
+             \"game_number\":\"1\",\"start_date_utc\":\"2024-01-01T00:00:00Z\",\
+             \"currency\":\"USD\",\"small_blind_amount\":1.0,\"big_blind_amount\":2.0,\
+             \"dealer_seat\":1,\"players\":[{{\"id\":1,\"seat\":1,\"name\":\"{player_name}\",\
+             \"starting_stack\":100.0}}],\"rounds\":[]}}}}"
+        )
+    }
+
+    #[test]
+    fn array_stream_handles_braces_and_escaped_quotes_inside_strings() {
+        // The table/player names below contain raw `{`/`}` and an escaped quote, which would
+        // desync a scanner that doesn't track string state separately from brace depth.
+        let hand = sample_hand_json("T{1}", r#"A \"Nit\" {Player}"#);
+        let input = format!("[{hand}]");
+        let mut output = Vec::new();
+
+        let report = convert_ohh_stream(Cursor::new(input), &mut output).unwrap();
+
+        assert_eq!(report.hands_converted, 1);
+        assert!(String::from_utf8(output).unwrap().contains("T{1}"));
+    }
+
+    #[test]
+    fn array_stream_handles_empty_array() {
+        let mut output = Vec::new();
+        let report = convert_ohh_stream(Cursor::new("[]"), &mut output).unwrap();
+
+        assert_eq!(report.hands_converted, 0);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn array_stream_tolerates_trailing_comma() {
+        let hand = sample_hand_json("T1", "Alice");
+        let input = format!("[{hand}, ]");
+        let mut output = Vec::new();
+
+        let report = convert_ohh_stream(Cursor::new(input), &mut output).unwrap();
+
+        assert_eq!(report.hands_converted, 1);
+    }
+
+    #[test]
+    fn ndjson_stream_tolerates_trailing_blank_line() {
+        let hand = sample_hand_json("T1", "Alice");
+        let input = format!("{hand}\n\n");
+        let mut output = Vec::new();
+
+        let report = convert_ohh_stream(Cursor::new(input), &mut output).unwrap();
+
+        assert_eq!(report.hands_converted, 1);
+    }
+
+    #[test]
+    fn stream_reports_one_based_hand_index_on_parse_error() {
+        let good = sample_hand_json("T1", "Alice");
+        let input = format!("{good}\nnot valid json\n");
+        let mut output = Vec::new();
+
+        let err = convert_ohh_stream(Cursor::new(input), &mut output).unwrap_err();
+
+        assert!(err.starts_with("hand 2:"), "unexpected error: {err}");
+    }
+}
@@ -1,7 +1,120 @@
 pub mod converter;
 
 use std::fs;
+use std::path::{Path, PathBuf};
 use log::{debug, info, warn, error};
+use serde::Deserialize;
+use tauri::Emitter;
+
+const MAX_FILE_SIZE: u64 = 100 * 1024 * 1024; // 100MB
+const CONVERTIBLE_EXTENSIONS: &[&str] = &["ohh", "txt", "json"];
+
+/// On-disk shape of `<app config dir>/pab-converter/allowlist.json`: the set of root
+/// directories conversion commands are permitted to read from.
+#[derive(Debug, Deserialize)]
+struct AllowlistConfig {
+    #[serde(default)]
+    allowed_roots: Vec<PathBuf>,
+}
+
+/// Canonicalized roots conversion commands are permitted to read from, loaded once at
+/// [`run()`] and handed to commands via Tauri's managed state.
+struct ReadPathAllowlist(Vec<PathBuf>);
+
+/// Default roots seeded when no allowlist config exists yet, so a first run can still convert
+/// files instead of denying everything with no discoverable way to fix it. Users can narrow or
+/// replace this by writing their own `allowed_roots` to the config file.
+fn default_allowlist_roots() -> Vec<PathBuf> {
+    dirs::document_dir()
+        .into_iter()
+        .chain(dirs::home_dir())
+        .filter_map(|root| root.canonicalize().ok())
+        .collect()
+}
+
+/// Loads the allowlist config from the app config dir. A missing config seeds
+/// [`default_allowlist_roots`]; an unparsable config, or a configured root that doesn't
+/// canonicalize, is logged and skipped rather than treated as fatal.
+fn load_allowlist_config() -> Vec<PathBuf> {
+    let config_path = dirs::config_dir()
+        .map(|d| d.join("pab-converter").join("allowlist.json"))
+        .unwrap_or_else(|| PathBuf::from("allowlist.json"));
+
+    let raw = match fs::read_to_string(&config_path) {
+        Ok(raw) => raw,
+        Err(e) => {
+            let defaults = default_allowlist_roots();
+            info!(
+                "No allowlist config at {:?} ({}); seeding default allowed root(s): {:?}",
+                config_path, e, defaults
+            );
+            return defaults;
+        }
+    };
+
+    let config: AllowlistConfig = match serde_json::from_str(&raw) {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Failed to parse allowlist config at {:?}: {}", config_path, e);
+            return Vec::new();
+        }
+    };
+
+    config
+        .allowed_roots
+        .into_iter()
+        .filter_map(|root| match root.canonicalize() {
+            Ok(canonical) => Some(canonical),
+            Err(e) => {
+                warn!("Skipping unreadable allowlist root {:?}: {}", root, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Rejects `canonical` unless it descends from one of `allowed_roots`, logging the denial. An
+/// empty allowlist is reported with a distinct, actionable message rather than the generic scope
+/// denial, since it means there's nothing configured to allow rather than a genuine out-of-scope
+/// path.
+fn enforce_allowlist(canonical: &Path, allowed_roots: &[PathBuf]) -> Result<(), String> {
+    if allowed_roots.is_empty() {
+        error!("Allowlist is empty; denying read of {:?}", canonical);
+        return Err(
+            "No allowlist configured: add at least one path to allowed_roots in allowlist.json (app config dir)"
+                .to_string(),
+        );
+    }
+
+    if allowed_roots.iter().any(|root| canonical.starts_with(root)) {
+        Ok(())
+    } else {
+        warn!("Denied read outside allowlist: {:?}", canonical);
+        Err("File path is outside the allowed directories".to_string())
+    }
+}
+
+/// Canonicalizes the parent directory of `output_path` and enforces `allowed_roots` against it,
+/// then rejoins the file name. The output file itself doesn't exist yet at this point, so we
+/// can't canonicalize the full path directly the way the read-side commands do.
+fn validate_output_path(output_path: &Path, allowed_roots: &[PathBuf]) -> Result<PathBuf, String> {
+    let file_name = output_path
+        .file_name()
+        .ok_or_else(|| "Output path must name a file".to_string())?;
+    let parent = match output_path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+
+    let canonical_parent = parent.canonicalize().map_err(|e| {
+        error!("Failed to canonicalize output directory: {}", e);
+        "Invalid output directory or directory does not exist".to_string()
+    })?;
+
+    enforce_allowlist(&canonical_parent, allowed_roots)?;
+
+    Ok(canonical_parent.join(file_name))
+}
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -9,10 +122,18 @@ fn greet(name: &str) -> String {
 }
 
 #[tauri::command]
-fn convert_ohh_content(content: String) -> Result<String, String> {
-    debug!("convert_ohh_content called with {} bytes", content.len());
+fn convert_ohh_content(
+    content: String,
+    output_format: Option<converter::OutputFormat>,
+) -> Result<String, String> {
+    let output_format = output_format.unwrap_or_default();
+    debug!(
+        "convert_ohh_content called with {} bytes, format {:?}",
+        content.len(),
+        output_format
+    );
 
-    match converter::convert_ohh_file(&content) {
+    match converter::convert_ohh_file_as(&content, output_format) {
         Ok(result) => Ok(result),
         Err(e) => {
             error!("conversion failed: {}", e);
@@ -21,50 +142,56 @@ fn convert_ohh_content(content: String) -> Result<String, String> {
     }
 }
 
-#[tauri::command]
-fn convert_ohh_file_path(file_path: String) -> Result<String, String> {
-    use std::path::Path;
-
-    debug!("convert_ohh_file_path called with: {}", file_path);
-
-    // Validate file path
-    let path = Path::new(&file_path);
-
-    // Ensure the path is absolute and doesn't contain directory traversal
-    let canonical = path
-        .canonicalize()
-        .map_err(|e| {
-            error!("Failed to canonicalize path: {}", e);
-            "Invalid file path or file does not exist".to_string()
-        })?;
+/// Canonicalizes `file_path`, enforces `allowed_roots` and checks its extension against
+/// [`CONVERTIBLE_EXTENSIONS`]. Shared by every command that reads a conversion input, since they
+/// all need the same directory-traversal and filesystem-scope guards.
+fn canonicalize_and_check_extension(
+    file_path: &Path,
+    allowed_roots: &[PathBuf],
+) -> Result<PathBuf, String> {
+    let canonical = file_path.canonicalize().map_err(|e| {
+        error!("Failed to canonicalize path: {}", e);
+        "Invalid file path or file does not exist".to_string()
+    })?;
 
     debug!("Canonical path: {:?}", canonical);
 
-    // Verify file extension
-    if let Some(ext) = canonical.extension() {
-        let ext_str = ext.to_str().unwrap_or("");
-        debug!("File extension: {}", ext_str);
-        if !matches!(ext_str, "ohh" | "txt" | "json") {
+    enforce_allowlist(&canonical, allowed_roots)?;
+
+    match canonical.extension().and_then(|e| e.to_str()) {
+        Some(ext_str) if CONVERTIBLE_EXTENSIONS.contains(&ext_str) => {
+            debug!("File extension: {}", ext_str);
+            Ok(canonical)
+        }
+        Some(_) => {
             let err = "Invalid file type. Only .ohh, .txt, or .json files are supported";
             error!("{}", err);
-            return Err(err.to_string());
+            Err(err.to_string())
+        }
+        None => {
+            let err = "File must have an extension";
+            error!("{}", err);
+            Err(err.to_string())
         }
-    } else {
-        let err = "File must have an extension";
-        error!("{}", err);
-        return Err(err.to_string());
     }
+}
 
-    // Check file size before reading (prevent DoS)
-    let metadata =
-        fs::metadata(&canonical).map_err(|e| {
-            error!("Failed to get file metadata: {}", e);
-            "Cannot access file".to_string()
-        })?;
+/// Canonicalizes, allowlist-checks and checks the extension of `file_path`, then enforces
+/// [`MAX_FILE_SIZE`]. Used by the non-streaming commands, which hold the whole file in memory at
+/// once.
+fn canonicalize_and_validate(
+    file_path: &Path,
+    allowed_roots: &[PathBuf],
+) -> Result<PathBuf, String> {
+    let canonical = canonicalize_and_check_extension(file_path, allowed_roots)?;
+
+    let metadata = fs::metadata(&canonical).map_err(|e| {
+        error!("Failed to get file metadata: {}", e);
+        "Cannot access file".to_string()
+    })?;
 
     debug!("File size: {} bytes", metadata.len());
 
-    const MAX_FILE_SIZE: u64 = 100 * 1024 * 1024; // 100MB
     if metadata.len() > MAX_FILE_SIZE {
         let err = format!(
             "File too large: {} MB (maximum 100 MB)",
@@ -74,18 +201,38 @@ fn convert_ohh_file_path(file_path: String) -> Result<String, String> {
         return Err(err);
     }
 
+    Ok(canonical)
+}
+
+#[tauri::command]
+fn convert_ohh_file_path(
+    file_path: String,
+    output_format: Option<converter::OutputFormat>,
+    allowlist: tauri::State<'_, ReadPathAllowlist>,
+) -> Result<converter::ConversionReport, String> {
+    let output_format = output_format.unwrap_or_default();
+    debug!(
+        "convert_ohh_file_path called with: {}, format {:?}",
+        file_path, output_format
+    );
+
+    let canonical = canonicalize_and_validate(Path::new(&file_path), &allowlist.0)?;
+
     debug!("Reading file content");
-    let content =
-        fs::read_to_string(&canonical).map_err(|e| {
-            error!("Failed to read file: {}", e);
-            "Failed to read file".to_string()
-        })?;
+    let content = fs::read_to_string(&canonical).map_err(|e| {
+        error!("Failed to read file: {}", e);
+        "Failed to read file".to_string()
+    })?;
 
     debug!("Read {} bytes, starting conversion", content.len());
-    match converter::convert_ohh_file(&content) {
-        Ok(result) => {
-            info!("File conversion successful, output size: {} bytes", result.len());
-            Ok(result)
+    match converter::convert_ohh_file_with_report(&content, output_format) {
+        Ok(report) => {
+            info!(
+                "File conversion successful, output size: {} bytes, {} diagnostic(s)",
+                report.output.len(),
+                report.diagnostics.len()
+            );
+            Ok(report)
         }
         Err(e) => {
             error!("File conversion failed: {}", e);
@@ -94,6 +241,205 @@ fn convert_ohh_file_path(file_path: String) -> Result<String, String> {
     }
 }
 
+#[tauri::command]
+fn validate_ohh_content(content: String) -> Result<Vec<converter::Diagnostic>, String> {
+    debug!("validate_ohh_content called with {} bytes", content.len());
+
+    converter::validate_ohh_content(&content).map_err(|e| {
+        error!("validation failed: {}", e);
+        format!("validation failed: {}", e)
+    })
+}
+
+/// Converts a hand-history file hand-by-hand, writing the result to `output_path` incrementally
+/// instead of holding the full input or output in memory. Unlike [`convert_ohh_file_path`] this
+/// has no [`MAX_FILE_SIZE`] cap, since memory use no longer scales with file size.
+#[tauri::command]
+fn convert_ohh_file_streaming(
+    input_path: String,
+    output_path: String,
+    allowlist: tauri::State<'_, ReadPathAllowlist>,
+) -> Result<converter::StreamConversionReport, String> {
+    debug!(
+        "convert_ohh_file_streaming called with input: {}, output: {}",
+        input_path, output_path
+    );
+
+    let canonical_input = canonicalize_and_check_extension(Path::new(&input_path), &allowlist.0)?;
+
+    let input_file = fs::File::open(&canonical_input).map_err(|e| {
+        error!("Failed to open input file: {}", e);
+        "Failed to open input file".to_string()
+    })?;
+
+    let canonical_output = validate_output_path(Path::new(&output_path), &allowlist.0)?;
+
+    let output_file = fs::File::create(&canonical_output).map_err(|e| {
+        error!("Failed to create output file: {}", e);
+        "Failed to create output file".to_string()
+    })?;
+    let writer = std::io::BufWriter::new(output_file);
+
+    match converter::convert_ohh_stream(input_file, writer) {
+        Ok(report) => {
+            info!(
+                "Streaming conversion complete: {} hands converted, {} diagnostic(s)",
+                report.hands_converted,
+                report.diagnostics.len()
+            );
+            Ok(report)
+        }
+        Err(e) => {
+            error!("Streaming conversion failed: {}", e);
+            Err(e)
+        }
+    }
+}
+
+/// Per-file outcome reported back to the frontend by [`convert_ohh_directory`].
+#[derive(Clone, serde::Serialize)]
+struct DirectoryConversionEntry {
+    file_name: String,
+    input_bytes: u64,
+    output_bytes: u64,
+    success: bool,
+    error: Option<String>,
+}
+
+/// Progress payload emitted as `conversion-progress` while a directory conversion runs.
+#[derive(Clone, serde::Serialize)]
+struct ConversionProgress {
+    current: usize,
+    total: usize,
+    file_name: String,
+}
+
+/// Recursively collects every file under `dir` whose extension is in
+/// [`CONVERTIBLE_EXTENSIONS`], walking subdirectories depth-first.
+fn collect_convertible_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    let entries = fs::read_dir(dir).map_err(|e| {
+        error!("Failed to read directory {:?}: {}", dir, e);
+        format!("Failed to read directory: {}", e)
+    })?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        let path = entry.path();
+        let metadata = entry
+            .metadata()
+            .map_err(|e| format!("Failed to get metadata for {:?}: {}", path, e))?;
+
+        if metadata.is_dir() {
+            files.extend(collect_convertible_files(&path)?);
+        } else if metadata.is_file() {
+            let is_convertible = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|ext| CONVERTIBLE_EXTENSIONS.contains(&ext));
+            if is_convertible {
+                files.push(path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[tauri::command]
+fn convert_ohh_directory(
+    app: tauri::AppHandle,
+    dir_path: String,
+    allowlist: tauri::State<'_, ReadPathAllowlist>,
+) -> Result<Vec<DirectoryConversionEntry>, String> {
+    debug!("convert_ohh_directory called with: {}", dir_path);
+
+    let dir = Path::new(&dir_path)
+        .canonicalize()
+        .map_err(|e| {
+            error!("Failed to canonicalize directory: {}", e);
+            "Invalid directory path or directory does not exist".to_string()
+        })?;
+
+    enforce_allowlist(&dir, &allowlist.0)?;
+
+    if !dir.is_dir() {
+        let err = "Path is not a directory".to_string();
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let files = collect_convertible_files(&dir)?;
+    let total = files.len();
+    info!("Converting {} files from {:?}", total, dir);
+
+    let mut results = Vec::with_capacity(total);
+    for (index, path) in files.iter().enumerate() {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string_lossy().into_owned());
+
+        let _ = app.emit(
+            "conversion-progress",
+            ConversionProgress {
+                current: index + 1,
+                total,
+                file_name: file_name.clone(),
+            },
+        );
+
+        let entry = match canonicalize_and_validate(path, &allowlist.0) {
+            Ok(canonical) => match fs::read_to_string(&canonical) {
+                Ok(content) => {
+                    let input_bytes = content.len() as u64;
+                    match converter::convert_ohh_file(&content) {
+                        Ok(result) => DirectoryConversionEntry {
+                            file_name,
+                            input_bytes,
+                            output_bytes: result.len() as u64,
+                            success: true,
+                            error: None,
+                        },
+                        Err(e) => DirectoryConversionEntry {
+                            file_name,
+                            input_bytes,
+                            output_bytes: 0,
+                            success: false,
+                            error: Some(e),
+                        },
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to read file {:?}: {}", canonical, e);
+                    DirectoryConversionEntry {
+                        file_name,
+                        input_bytes: 0,
+                        output_bytes: 0,
+                        success: false,
+                        error: Some("Failed to read file".to_string()),
+                    }
+                }
+            },
+            Err(e) => DirectoryConversionEntry {
+                file_name,
+                input_bytes: 0,
+                output_bytes: 0,
+                success: false,
+                error: Some(e),
+            },
+        };
+
+        if !entry.success {
+            warn!("Conversion failed for {}: {:?}", entry.file_name, entry.error);
+        }
+        results.push(entry);
+    }
+
+    info!("Directory conversion complete: {} files processed", total);
+    Ok(results)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Initialize logging to file
@@ -149,13 +495,20 @@ pub fn run() {
     info!("Debug mode: {}", cfg!(debug_assertions));
     info!("Log directory: {:?}", logs_dir);
 
+    let allowed_roots = load_allowlist_config();
+    info!("Loaded {} allowed read root(s)", allowed_roots.len());
+
     tauri::Builder::default()
+        .manage(ReadPathAllowlist(allowed_roots))
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
         .invoke_handler(tauri::generate_handler![
             greet,
             convert_ohh_content,
-            convert_ohh_file_path
+            convert_ohh_file_path,
+            convert_ohh_file_streaming,
+            convert_ohh_directory,
+            validate_ohh_content
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");